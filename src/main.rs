@@ -1,4 +1,5 @@
 use sui_sdk::SuiClientBuilder;
+use sui_sdk::types::crypto::SignatureScheme;
 mod utils;
 
 #[tokio::main]
@@ -16,7 +17,7 @@ async fn main() -> Result<(), anyhow::Error> {
     println!("Sui mainnet version: {}", sui_mainnet.api_version());
 
     // Example usage of utils modules
-    let mut wallet = utils::wallet::retrieve_wallet()?;
+    let mut wallet = utils::wallet::retrieve_wallet(SignatureScheme::ED25519)?;
     let active_address = wallet.active_address()?;
     println!("Wallet active address: {:?}", active_address);
 
@@ -0,0 +1,36 @@
+use sui_sdk::SuiClient;
+use sui_sdk::types::base_types::SuiAddress;
+use anyhow::{anyhow, Result};
+
+/// Requests SUI tokens for `address` from the faucet matching the chain the
+/// client is currently connected to (devnet or testnet).
+pub async fn request_tokens_from_faucet(address: SuiAddress, sui: &SuiClient) -> Result<()> {
+    let faucet_url = match sui.read_api().get_chain_identifier().await?.as_str() {
+        "4c78adac" => "https://faucet.devnet.sui.io/v1/gas",
+        "35834a8a" => "https://faucet.testnet.sui.io/v1/gas",
+        chain_id => return Err(anyhow!("No known faucet for chain id {chain_id}")),
+    };
+
+    let data = serde_json::json!({
+        "FixedAmountRequest": {
+            "recipient": address.to_string()
+        }
+    });
+
+    let client = reqwest::Client::new();
+    let response = client
+        .post(faucet_url)
+        .header("Content-Type", "application/json")
+        .body(data.to_string())
+        .send()
+        .await?;
+
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "Faucet request for {address} failed with status {}",
+            response.status()
+        ));
+    }
+
+    Ok(())
+}
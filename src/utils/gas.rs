@@ -0,0 +1,131 @@
+use sui_sdk::{
+    SuiClient,
+    types::{
+        base_types::{ObjectRef, SuiAddress},
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        transaction::TransactionData,
+    },
+};
+use sui_json_rpc_types::SuiTransactionBlockEffectsAPI;
+use anyhow::{anyhow, Result};
+
+/// Safety margin (in MIST) added on top of the computation + storage cost
+/// reported by the dry run, so that small price fluctuations between the dry
+/// run and the real execution don't cause an `InsufficientGas` failure.
+const GAS_SAFETY_MARGIN: u64 = 2_000_000;
+
+/// A budget big enough for the provisional, dry-run-only `TransactionData` to
+/// pass validation. It is never actually spent since the dry run does not
+/// touch gas objects.
+const PROVISIONAL_GAS_BUDGET: u64 = 50_000_000_000;
+
+/// Finishes a [`ProgrammableTransactionBuilder`] into ready-to-sign
+/// [`TransactionData`] with the gas payment and budget resolved
+/// automatically, instead of the caller hardcoding `gas_budget = 10_000_000`
+/// and grabbing whatever coin `get_coins` happens to return first.
+///
+/// If `gas_coins` is `None`, the minimal set of the sender's owned
+/// `0x2::sui::SUI` coins covering the resolved budget is selected
+/// automatically (sorted by balance, descending, greedily accumulated).
+pub async fn resolve_transaction(
+    sui: &SuiClient,
+    builder: ProgrammableTransactionBuilder,
+    sender: SuiAddress,
+    gas_coins: Option<Vec<ObjectRef>>,
+) -> Result<TransactionData> {
+    let gas_price = sui.read_api().get_reference_gas_price().await?;
+    let pt = builder.finish();
+
+    // A placeholder gas payment just to get a well-formed TransactionData to
+    // dry run; the dry run doesn't actually debit it.
+    let provisional_gas = match &gas_coins {
+        Some(coins) => coins.clone(),
+        None => select_gas_coins(sui, sender, GAS_SAFETY_MARGIN).await?,
+    };
+
+    // 1) construct a provisional TransactionData with a placeholder max budget.
+    let provisional_tx_data = TransactionData::new_programmable(
+        sender,
+        provisional_gas,
+        pt.clone(),
+        PROVISIONAL_GAS_BUDGET,
+        gas_price,
+    );
+
+    // 2) dry run it to find out what it actually costs.
+    let dry_run = sui
+        .read_api()
+        .dry_run_transaction_block(provisional_tx_data)
+        .await?;
+    let cost = dry_run.effects.gas_cost_summary();
+
+    // 3) the real budget is computation + storage, net of the rebate we get
+    // back for the storage we're freeing, plus a safety margin.
+    let net_cost =
+        (cost.computation_cost + cost.storage_cost).saturating_sub(cost.storage_rebate);
+    let gas_budget = net_cost + GAS_SAFETY_MARGIN;
+
+    // 4) pick the gas payment for the real transaction: the caller's explicit
+    // list if they gave one, otherwise the minimal covering set of coins for
+    // the now-known budget.
+    let resolved_gas = match gas_coins {
+        Some(coins) => coins,
+        None => select_gas_coins(sui, sender, gas_budget).await?,
+    };
+
+    Ok(TransactionData::new_programmable(
+        sender,
+        resolved_gas,
+        pt,
+        gas_budget,
+        gas_price,
+    ))
+}
+
+/// Greedily selects the minimal set of `sender`'s owned `0x2::sui::SUI` coins
+/// (sorted descending by balance) whose combined balance covers `budget`.
+async fn select_gas_coins(
+    sui: &SuiClient,
+    sender: SuiAddress,
+    budget: u64,
+) -> Result<Vec<ObjectRef>> {
+    let mut coins = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = sui
+            .coin_read_api()
+            .get_coins(sender, Some("0x2::sui::SUI".to_string()), cursor, None)
+            .await?;
+        coins.extend(page.data);
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+
+    if coins.is_empty() {
+        return Err(anyhow!(
+            "Address {sender} has no 0x2::sui::SUI coins to pay for gas with"
+        ));
+    }
+
+    coins.sort_by(|a, b| b.balance.cmp(&a.balance));
+
+    let mut selected = Vec::new();
+    let mut accumulated = 0u64;
+    for coin in coins {
+        if accumulated >= budget {
+            break;
+        }
+        accumulated += coin.balance;
+        selected.push(coin.object_ref());
+    }
+
+    if accumulated < budget {
+        return Err(anyhow!(
+            "Address {sender} does not have enough SUI to cover a gas budget of {budget} MIST"
+        ));
+    }
+
+    Ok(selected)
+}
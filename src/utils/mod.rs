@@ -0,0 +1,8 @@
+pub mod faucet;
+pub mod gas;
+pub mod pure_arg;
+pub mod queue;
+pub mod report;
+pub mod sponsor;
+pub mod transaction;
+pub mod wallet;
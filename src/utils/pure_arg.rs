@@ -0,0 +1,116 @@
+use anyhow::{anyhow, Result};
+use sui_json_rpc_types::SuiMoveNormalizedType;
+use sui_sdk::{
+    types::{
+        base_types::{ObjectID, SuiAddress},
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        transaction::Argument,
+    },
+    SuiClient,
+};
+
+/// A `CommandArgumentError::InvalidBCSBytes` is what you get when the Rust
+/// type handed to `ptb.pure(...)` doesn't match the Move parameter's type --
+/// and the wallet only tells you after gas is burned. This module gives typed
+/// constructors for the common cases and an optional pre-submission check
+/// ([`validate_pure_args`]) against the target function's normalized
+/// signature; `PureArgKind` names the kind of each pure argument to check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PureArgKind {
+    U64,
+    Address,
+    VecU8,
+    Str,
+    ObjectId,
+}
+
+impl PureArgKind {
+    /// Whether a pure argument of this kind is a plausible BCS encoding of
+    /// `expected`, the Move parameter type reported by
+    /// `get_normalized_move_function`.
+    fn matches(self, expected: &SuiMoveNormalizedType) -> bool {
+        match (self, expected) {
+            (PureArgKind::U64, SuiMoveNormalizedType::U64) => true,
+            (PureArgKind::Address, SuiMoveNormalizedType::Address) => true,
+            (PureArgKind::ObjectId, SuiMoveNormalizedType::Address) => true,
+            (PureArgKind::VecU8, SuiMoveNormalizedType::Vector(inner)) => {
+                matches!(**inner, SuiMoveNormalizedType::U8)
+            }
+            // `0x1::string::String` and `0x1::ascii::String` are both single
+            // `vector<u8>`-backed structs, BCS-compatible with a plain `&str`.
+            (PureArgKind::Str, SuiMoveNormalizedType::Struct { module, name, .. }) => {
+                (module == "string" || module == "ascii") && name == "String"
+            }
+            _ => false,
+        }
+    }
+}
+
+/// `ptb.pure(1000u64)` compiles for any type that implements `Serialize`, so
+/// a typo like passing a `u32` where the Move function expects a `u64`
+/// serializes fine in Rust and only fails once the network executes the
+/// command. These wrappers pin down the Rust type at the call site, so that
+/// kind of mismatch is a compile error instead of an on-chain one.
+pub fn pure_u64(ptb: &mut ProgrammableTransactionBuilder, value: u64) -> Result<Argument> {
+    ptb.pure(value)
+}
+
+pub fn pure_address(ptb: &mut ProgrammableTransactionBuilder, value: SuiAddress) -> Result<Argument> {
+    ptb.pure(value)
+}
+
+pub fn pure_object_id(ptb: &mut ProgrammableTransactionBuilder, value: ObjectID) -> Result<Argument> {
+    ptb.pure(value)
+}
+
+pub fn pure_vec_u8(ptb: &mut ProgrammableTransactionBuilder, value: Vec<u8>) -> Result<Argument> {
+    ptb.pure(value)
+}
+
+/// Encodes a Rust string as a Move `0x1::string::String` (or
+/// `0x1::ascii::String`) argument -- both are BCS-identical to the `vector<u8>`
+/// of their bytes, since a single-field struct has no BCS framing of its own.
+pub fn pure_string(ptb: &mut ProgrammableTransactionBuilder, value: impl AsRef<str>) -> Result<Argument> {
+    ptb.pure(value.as_ref().as_bytes().to_vec())
+}
+
+/// Encodes `value` as a Move `vector<T>` of length 0 or 1, matching how
+/// `0x1::option::Option<T>` is BCS-encoded.
+pub fn pure_option<T: serde::Serialize>(
+    ptb: &mut ProgrammableTransactionBuilder,
+    value: Option<T>,
+) -> Result<Argument> {
+    ptb.pure(value.into_iter().collect::<Vec<_>>())
+}
+
+/// Fetches `package::module::function`'s normalized signature and checks
+/// that each `(parameter_index, kind)` pair in `pure_args` matches the Move
+/// parameter at that index, returning a descriptive error naming the
+/// offending argument instead of letting a mismatch reach the chain.
+pub async fn validate_pure_args(
+    sui: &SuiClient,
+    package: ObjectID,
+    module: &str,
+    function: &str,
+    pure_args: &[(usize, PureArgKind)],
+) -> Result<()> {
+    let normalized = sui
+        .read_api()
+        .get_normalized_move_function(package, module.to_string(), function.to_string())
+        .await?;
+
+    for (index, kind) in pure_args {
+        let expected = normalized.parameters.get(*index).ok_or_else(|| {
+            anyhow!("{module}::{function} has no parameter at index {index}")
+        })?;
+
+        if !kind.matches(expected) {
+            return Err(anyhow!(
+                "argument {index} for {module}::{function} is a {kind:?}, \
+                 but the function expects {expected:?}"
+            ));
+        }
+    }
+
+    Ok(())
+}
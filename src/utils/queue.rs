@@ -0,0 +1,186 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::Arc;
+
+use anyhow::Result;
+use shared_crypto::intent::Intent;
+use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
+use sui_json_rpc_types::SuiTransactionBlockResponseOptions;
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
+use sui_sdk::{
+    SuiClient,
+    types::{
+        base_types::{ObjectID, SuiAddress},
+        digests::TransactionDigest,
+        quorum_driver_types::ExecuteTransactionRequestType,
+        transaction::{CallArg, ObjectArg, Transaction, TransactionData, TransactionKind},
+    },
+};
+use tokio::sync::{oneshot, Mutex};
+
+/// Submitting two transactions that both lock the same owned object
+/// (including a gas coin) in the same epoch wedges the sender until epoch
+/// end. `TransactionQueue` serializes submission on a per-object basis so
+/// callers can fire many transactions concurrently without hand-rolling that
+/// coordination themselves.
+#[derive(Clone)]
+pub struct TransactionQueue {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    sui: SuiClient,
+    max_per_sender: Option<usize>,
+    state: Mutex<QueueState>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    /// Object IDs (inputs + gas coins) currently locked by an in-flight transaction.
+    reserved: HashSet<ObjectID>,
+    /// Transactions not yet ready to submit, in FIFO arrival order.
+    pending: VecDeque<QueuedTx>,
+    /// Number of in-flight transactions per sender, for the concurrency cap.
+    in_flight_per_sender: HashMap<SuiAddress, usize>,
+}
+
+struct QueuedTx {
+    tx_data: TransactionData,
+    object_ids: HashSet<ObjectID>,
+    sender: SuiAddress,
+    reply: oneshot::Sender<Result<TransactionDigest>>,
+}
+
+impl TransactionQueue {
+    /// Creates a queue backed by `sui`. `max_per_sender` caps how many of a
+    /// single sender's transactions may be in flight at once; `None` means
+    /// unbounded (still serialized by object conflict).
+    pub fn new(sui: SuiClient, max_per_sender: Option<usize>) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                sui,
+                max_per_sender,
+                state: Mutex::new(QueueState::default()),
+            }),
+        }
+    }
+
+    /// Queues `tx_data` for submission and resolves to its digest once it
+    /// has executed. The transaction is held back (*pending*) for as long as
+    /// any of its input objects or gas coins are reserved by another
+    /// in-flight transaction, and becomes *ready* the moment they clear.
+    pub async fn enqueue(&self, tx_data: TransactionData) -> Result<TransactionDigest> {
+        let sender = tx_data.sender();
+        let object_ids = conflicting_object_ids(&tx_data);
+        let (reply, receiver) = oneshot::channel();
+
+        {
+            let mut state = self.inner.state.lock().await;
+            state.pending.push_back(QueuedTx {
+                tx_data,
+                object_ids,
+                sender,
+                reply,
+            });
+        }
+
+        self.dispatch_ready().await;
+        receiver.await?
+    }
+
+    /// Promotes every currently-ready pending transaction to in-flight,
+    /// reserving its objects and spawning its submission. Called once per
+    /// enqueue and again after each submission releases its reservations, so
+    /// transactions that were pending on it get a chance to run.
+    async fn dispatch_ready(&self) {
+        loop {
+            let ready = {
+                let mut state = self.inner.state.lock().await;
+                let cap = self.inner.max_per_sender.unwrap_or(usize::MAX);
+                let index = state.pending.iter().position(|queued| {
+                    queued.object_ids.is_disjoint(&state.reserved)
+                        && state
+                            .in_flight_per_sender
+                            .get(&queued.sender)
+                            .copied()
+                            .unwrap_or(0)
+                            < cap
+                });
+
+                match index {
+                    Some(index) => {
+                        let queued = state.pending.remove(index).expect("index just checked");
+                        state.reserved.extend(queued.object_ids.iter().copied());
+                        *state.in_flight_per_sender.entry(queued.sender).or_insert(0) += 1;
+                        queued
+                    }
+                    None => break,
+                }
+            };
+
+            let queue = self.clone();
+            tokio::spawn(async move {
+                let result = submit(&queue.inner.sui, &ready.tx_data).await;
+
+                {
+                    let mut state = queue.inner.state.lock().await;
+                    for id in &ready.object_ids {
+                        state.reserved.remove(id);
+                    }
+                    if let Some(count) = state.in_flight_per_sender.get_mut(&ready.sender) {
+                        *count = count.saturating_sub(1);
+                    }
+                }
+
+                let _ = ready.reply.send(result);
+                // Releasing these reservations may have unblocked other
+                // pending transactions; give them a chance to run.
+                queue.dispatch_ready().await;
+            });
+        }
+    }
+}
+
+/// Signs and executes `tx_data`, waiting for local execution so the caller
+/// gets a digest it can trust has been applied.
+async fn submit(sui: &SuiClient, tx_data: &TransactionData) -> Result<TransactionDigest> {
+    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
+    let signature = keystore.sign_secure(&tx_data.sender(), tx_data, Intent::sui_transaction())?;
+
+    let response = sui
+        .quorum_driver_api()
+        .execute_transaction_block(
+            Transaction::from_data(tx_data.clone(), vec![signature]),
+            SuiTransactionBlockResponseOptions::new(),
+            Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+        )
+        .await?;
+
+    Ok(response.digest)
+}
+
+/// The set of object IDs this transaction would lock: its gas payment plus
+/// every owned/shared/immutable object it takes as input. Two transactions
+/// that share any of these IDs cannot be submitted concurrently.
+fn conflicting_object_ids(tx_data: &TransactionData) -> HashSet<ObjectID> {
+    let mut ids: HashSet<ObjectID> = tx_data
+        .gas_data()
+        .payment
+        .iter()
+        .map(|(id, _version, _digest)| *id)
+        .collect();
+
+    if let TransactionKind::ProgrammableTransaction(pt) = tx_data.kind() {
+        for input in &pt.inputs {
+            if let CallArg::Object(object_arg) = input {
+                let id = match object_arg {
+                    ObjectArg::ImmOrOwnedObject((id, _, _)) => *id,
+                    ObjectArg::SharedObject { id, .. } => *id,
+                    ObjectArg::Receiving((id, _, _)) => *id,
+                };
+                ids.insert(id);
+            }
+        }
+    }
+
+    ids
+}
@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use anyhow::{anyhow, Result};
+use sui_json_rpc_types::{
+    SuiExecutionStatus, SuiTransactionBlockEffectsAPI, SuiTransactionBlockResponseOptions,
+};
+use sui_sdk::{
+    types::{base_types::{ObjectID, SuiAddress}, digests::TransactionDigest},
+    SuiClient,
+};
+use sui_types::object::Owner;
+
+/// How long to wait between polls of `get_transaction_block` while waiting
+/// for the transaction's checkpoint to be assigned.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// The net change in a single address's balance of a single coin type caused
+/// by a transaction.
+///
+/// Only changes owned by a plain address (`Owner::AddressOwner`) are
+/// reported; changes to object-owned or shared coins are dropped, since the
+/// callers this is built for (the move-call binaries) only ever care about
+/// what happened to a wallet address's balance.
+#[derive(Debug, Clone)]
+pub struct BalanceChange {
+    pub coin_type: String,
+    pub address: SuiAddress,
+    /// Signed delta in MIST: negative if the address paid out more than it received.
+    pub amount: i128,
+}
+
+/// A structured view of what an executed transaction actually did, in place
+/// of printing the raw `SuiTransactionBlockResponse`.
+#[derive(Debug, Clone)]
+pub struct TxOutcome {
+    pub digest: TransactionDigest,
+    pub success: bool,
+    pub created: Vec<ObjectID>,
+    pub mutated: Vec<ObjectID>,
+    pub deleted: Vec<ObjectID>,
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+/// Waits for `digest` to reach finality (its checkpoint to be assigned) and
+/// returns a [`TxOutcome`] summarizing its effects and balance changes,
+/// instead of the caller having to `println!` the raw response and eyeball
+/// whether it succeeded.
+pub async fn confirm_and_report(sui: &SuiClient, digest: TransactionDigest) -> Result<TxOutcome> {
+    let options = SuiTransactionBlockResponseOptions::full_content();
+
+    let response = loop {
+        let response = sui
+            .read_api()
+            .get_transaction_block(digest, options.clone())
+            .await?;
+
+        if response.checkpoint.is_some() {
+            break response;
+        }
+
+        tokio::time::sleep(POLL_INTERVAL).await;
+    };
+
+    let effects = response
+        .effects
+        .ok_or_else(|| anyhow!("Transaction {digest} has no effects to report on"))?;
+
+    let success = matches!(effects.status(), SuiExecutionStatus::Success);
+    let created = effects
+        .created()
+        .iter()
+        .map(|o| o.reference.object_id)
+        .collect();
+    let mutated = effects
+        .mutated()
+        .iter()
+        .map(|o| o.reference.object_id)
+        .collect();
+    let deleted = effects.deleted().iter().map(|o| o.object_id).collect();
+
+    let balance_changes = response
+        .balance_changes
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|change| match change.owner {
+            Owner::AddressOwner(address) => Some(BalanceChange {
+                coin_type: change.coin_type.to_string(),
+                address,
+                amount: change.amount,
+            }),
+            _ => None,
+        })
+        .collect();
+
+    Ok(TxOutcome {
+        digest,
+        success,
+        created,
+        mutated,
+        deleted,
+        balance_changes,
+    })
+}
@@ -0,0 +1,65 @@
+use anyhow::Result;
+use shared_crypto::intent::Intent;
+use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
+use sui_json_rpc_types::SuiTransactionBlockResponseOptions;
+use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
+use sui_sdk::{
+    types::{
+        base_types::{ObjectRef, SuiAddress},
+        digests::TransactionDigest,
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        quorum_driver_types::ExecuteTransactionRequestType,
+        transaction::{Transaction, TransactionData},
+    },
+    SuiClient,
+};
+
+/// Finishes a [`ProgrammableTransactionBuilder`] into `TransactionData` where
+/// `sponsor` pays gas from `sponsor_gas` on behalf of `sender`, instead of
+/// assuming (as every other signing path in this crate does) that the
+/// sender also owns the gas payment.
+pub async fn build_sponsored_transaction(
+    sui: &SuiClient,
+    builder: ProgrammableTransactionBuilder,
+    sender: SuiAddress,
+    sponsor: SuiAddress,
+    sponsor_gas: Vec<ObjectRef>,
+    gas_budget: u64,
+) -> Result<TransactionData> {
+    let gas_price = sui.read_api().get_reference_gas_price().await?;
+    let pt = builder.finish();
+
+    Ok(TransactionData::new_programmable_allow_sponsor(
+        sender,
+        sponsor_gas,
+        pt,
+        gas_budget,
+        gas_price,
+        sponsor,
+    ))
+}
+
+/// Collects the sender's intent signature and the sponsor's signature over
+/// the same `tx_data` and executes it, waiting for local execution.
+pub async fn execute_sponsored(
+    sui: &SuiClient,
+    tx_data: TransactionData,
+    sender: SuiAddress,
+    sponsor: SuiAddress,
+) -> Result<TransactionDigest> {
+    let keystore = FileBasedKeystore::new(&sui_config_dir()?.join(SUI_KEYSTORE_FILENAME))?;
+
+    let sender_signature = keystore.sign_secure(&sender, &tx_data, Intent::sui_transaction())?;
+    let sponsor_signature = keystore.sign_secure(&sponsor, &tx_data, Intent::sui_transaction())?;
+
+    let response = sui
+        .quorum_driver_api()
+        .execute_transaction_block(
+            Transaction::from_data(tx_data, vec![sender_signature, sponsor_signature]),
+            SuiTransactionBlockResponseOptions::full_content(),
+            Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+        )
+        .await?;
+
+    Ok(response.digest)
+}
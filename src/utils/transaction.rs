@@ -1,21 +1,64 @@
 use sui_sdk::{
     SuiClient,
     types::{
-        base_types::SuiAddress,
+        base_types::{ObjectID, SuiAddress},
         digests::TransactionDigest,
-        transaction::{Transaction, TransactionData, Argument, Command},
+        transaction::{Transaction, TransactionData, Argument, Command, ObjectArg},
         programmable_transaction_builder::ProgrammableTransactionBuilder,
         quorum_driver_types::ExecuteTransactionRequestType,
     },
+    rpc_types::SuiObjectDataOptions,
 };
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
 use sui_json_rpc_types::{SuiTransactionBlockResponseOptions, Coin};
+use sui_types::object::Owner;
 use shared_crypto::intent::Intent;
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use futures::{future, stream::StreamExt};
 use sui_config::{sui_config_dir, SUI_KEYSTORE_FILENAME};
 use super::faucet::request_tokens_from_faucet;
 
+/// Fetches `id` and builds the `ObjectArg` matching how it is owned, so
+/// callers can add any object to a PTB by ID alone instead of manually
+/// querying it and matching on `Owner` themselves (the 20-line block
+/// duplicated across the counter-style examples).
+///
+/// `mutable` only affects the `SharedObject` case; owned and immutable
+/// objects are always passed by their exact object reference.
+pub async fn resolve_object_arg(
+    sui: &SuiClient,
+    id: ObjectID,
+    mutable: bool,
+) -> Result<ObjectArg> {
+    let object = sui
+        .read_api()
+        .get_object_with_options(id, SuiObjectDataOptions::full_content())
+        .await?;
+
+    let data = object
+        .data
+        .ok_or_else(|| anyhow!("Object {id} not found"))?;
+    let owner = data
+        .owner
+        .ok_or_else(|| anyhow!("Object {id} has no owner information"))?;
+
+    match owner {
+        Owner::Shared {
+            initial_shared_version,
+        } => Ok(ObjectArg::SharedObject {
+            id,
+            initial_shared_version,
+            mutable,
+        }),
+        Owner::AddressOwner(_) | Owner::ObjectOwner(_) | Owner::Immutable => {
+            Ok(ObjectArg::ImmOrOwnedObject(data.object_ref()))
+        }
+        other => Err(anyhow!(
+            "Object {id} has an owner kind that cannot be resolved to an ObjectArg: {other:?}"
+        )),
+    }
+}
+
 /// Return the coin owned by the address that has at least 5_000_000 MIST, otherwise returns None
 pub async fn fetch_coin(
     sui: &SuiClient,
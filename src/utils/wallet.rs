@@ -3,17 +3,32 @@ use sui_sdk::{
     wallet_context::WalletContext,
     SuiClient,
     SuiClientBuilder,
-    types::base_types::SuiAddress,
+    rpc_types::{SuiObjectDataOptions, SuiObjectResponseQuery},
+    types::{
+        base_types::SuiAddress,
+        programmable_transaction_builder::ProgrammableTransactionBuilder,
+        quorum_driver_types::ExecuteTransactionRequestType,
+        transaction::{Argument, Command, ObjectArg, Transaction},
+    },
 };
 use sui_config::{sui_config_dir, PersistedConfig, Config, SUI_CLIENT_CONFIG, SUI_KEYSTORE_FILENAME};
 use sui_keys::keystore::{AccountKeystore, FileBasedKeystore};
-use anyhow::Result;
+use sui_json_rpc_types::SuiTransactionBlockResponseOptions;
+use anyhow::{anyhow, Result};
 use tracing::info;
-use sui_sdk::types::crypto::SignatureScheme::ED25519;
+use shared_crypto::intent::Intent;
+use sui_sdk::types::crypto::SignatureScheme;
 use super::faucet::request_tokens_from_faucet;
+use super::gas::resolve_transaction;
 use super::transaction::fetch_coin;
 
-pub fn retrieve_wallet() -> Result<WalletContext, anyhow::Error> {
+/// Ensures a wallet exists (creating its keystore and client config on first
+/// run) and that its keystore holds at least two addresses, generating new
+/// keys with `scheme` for whichever of the first two addresses don't exist
+/// yet. Once the keystore already holds two or more addresses, `scheme` is
+/// ignored and no new key is added -- use [`add_key`] to append a key of a
+/// specific scheme to a wallet that's already fully bootstrapped.
+pub fn retrieve_wallet(scheme: SignatureScheme) -> Result<WalletContext, anyhow::Error> {
     let wallet_conf = sui_config_dir()?.join(SUI_CLIENT_CONFIG);
     let keystore_path = sui_config_dir()?.join(SUI_KEYSTORE_FILENAME);
 
@@ -45,13 +60,11 @@ pub fn retrieve_wallet() -> Result<WalletContext, anyhow::Error> {
     let default_active_address = if let Some(address) = keystore.addresses().first() {
         *address
     } else {
-        keystore
-            .generate_and_add_new_key(ED25519, None, None, None)?
-            .0
+        keystore.generate_and_add_new_key(scheme, None, None, None)?.0
     };
 
     if keystore.addresses().len() < 2 {
-        keystore.generate_and_add_new_key(ED25519, None, None, None)?;
+        keystore.generate_and_add_new_key(scheme, None, None, None)?;
     }
 
     client_config.active_address = Some(default_active_address);
@@ -62,6 +75,111 @@ pub fn retrieve_wallet() -> Result<WalletContext, anyhow::Error> {
     Ok(wallet)
 }
 
+/// Generates and appends a key of `scheme` to the existing keystore,
+/// regardless of how many addresses it already holds. This is how callers
+/// add e.g. a `Secp256k1` or `Secp256r1` account to a wallet that
+/// `retrieve_wallet` already bootstrapped with `Ed25519` keys.
+pub fn add_key(scheme: SignatureScheme) -> Result<SuiAddress> {
+    let keystore_path = sui_config_dir()?.join(SUI_KEYSTORE_FILENAME);
+    let mut keystore = FileBasedKeystore::new(&keystore_path)?;
+    let (address, _phrase, _scheme) = keystore.generate_and_add_new_key(scheme, None, None, None)?;
+    Ok(address)
+}
+
+/// Generates a fresh key of the given `scheme`, moves every coin and object
+/// owned by the wallet's current active address to it via a single PTB, and
+/// then makes it the new active address. This is the scripted equivalent of
+/// a key-rotation fire drill: no manual keystore editing required.
+///
+/// Returns the new active address.
+pub async fn rotate_active_key(
+    sui: &SuiClient,
+    scheme: SignatureScheme,
+) -> Result<SuiAddress> {
+    let wallet_conf = sui_config_dir()?.join(SUI_CLIENT_CONFIG);
+    let keystore_path = sui_config_dir()?.join(SUI_KEYSTORE_FILENAME);
+
+    let mut keystore = FileBasedKeystore::new(&keystore_path)?;
+    let mut client_config: SuiClientConfig = PersistedConfig::read(&wallet_conf)?;
+
+    let old_address = client_config
+        .active_address
+        .ok_or_else(|| anyhow!("No active address to rotate away from"))?;
+
+    let (new_address, _phrase, _scheme) =
+        keystore.generate_and_add_new_key(scheme, None, None, None)?;
+
+    // Page through every object the old address owns, splitting its SUI
+    // coins (which will all be handed to the PTB as gas payment, so that
+    // Sui's gas-smashing merges them into one coin we can then transfer in
+    // full) from everything else (which is moved via a regular
+    // TransferObjects input).
+    const SUI_COIN_TYPE: &str = "0x2::coin::Coin<0x2::sui::SUI>";
+    let query = SuiObjectResponseQuery::new_with_options(SuiObjectDataOptions::full_content());
+
+    let mut sui_coins = Vec::new();
+    let mut other_objects = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = sui
+            .read_api()
+            .get_owned_objects(old_address, Some(query.clone()), cursor, None)
+            .await?;
+
+        for object in page.data {
+            let Some(data) = object.data else { continue };
+            let object_ref = data.object_ref();
+            match data.type_.as_ref().map(ToString::to_string) {
+                Some(type_) if type_ == SUI_COIN_TYPE => sui_coins.push(object_ref),
+                _ => other_objects.push(object_ref),
+            }
+        }
+
+        if !page.has_next_page {
+            break;
+        }
+        cursor = page.next_cursor;
+    }
+
+    if sui_coins.is_empty() {
+        return Err(anyhow!(
+            "Old active address {old_address} has no SUI to pay gas with for rotation"
+        ));
+    }
+
+    let mut ptb = ProgrammableTransactionBuilder::new();
+    let mut transfer_args = Vec::with_capacity(other_objects.len() + 1);
+    for object_ref in other_objects {
+        transfer_args.push(ptb.obj(ObjectArg::ImmOrOwnedObject(object_ref))?);
+    }
+    // The gas coin, after gas-smashing merges every SUI coin into it and the
+    // network deducts the fee, still holds the rest of the old address's
+    // SUI balance -- transfer it too, so nothing is left stranded behind.
+    transfer_args.push(Argument::GasCoin);
+    let recipient = ptb.pure(new_address)?;
+    ptb.command(Command::TransferObjects(transfer_args, recipient));
+
+    // Hand every SUI coin to resolve_transaction as the explicit gas
+    // payment: Sui smashes them into one before execution, and
+    // resolve_transaction dry-runs the real budget instead of a hardcoded
+    // guess that could undershoot a thinly-funded coin.
+    let tx_data = resolve_transaction(sui, ptb, old_address, Some(sui_coins)).await?;
+
+    let signature = keystore.sign_secure(&old_address, &tx_data, Intent::sui_transaction())?;
+    sui.quorum_driver_api()
+        .execute_transaction_block(
+            Transaction::from_data(tx_data, vec![signature]),
+            SuiTransactionBlockResponseOptions::new(),
+            Some(ExecuteTransactionRequestType::WaitForLocalExecution),
+        )
+        .await?;
+
+    client_config.active_address = Some(new_address);
+    client_config.save(&wallet_conf)?;
+
+    Ok(new_address)
+}
+
 pub async fn setup_for_write() -> Result<(SuiClient, SuiAddress, SuiAddress), anyhow::Error> {
     let (client, active_address) = setup_for_read().await?;
     // make sure we have some SUI (5_000_000 MIST) on this address
@@ -69,7 +187,7 @@ pub async fn setup_for_write() -> Result<(SuiClient, SuiAddress, SuiAddress), an
     if coin.is_none() {
         request_tokens_from_faucet(active_address, &client).await?;
     }
-    let wallet = retrieve_wallet()?;
+    let wallet = retrieve_wallet(SignatureScheme::ED25519)?;
     let addresses = wallet.get_addresses();
     let addresses = addresses
         .into_iter()
@@ -85,10 +203,32 @@ pub async fn setup_for_write() -> Result<(SuiClient, SuiAddress, SuiAddress), an
 pub async fn setup_for_read() -> Result<(SuiClient, SuiAddress), anyhow::Error> {
     let client = SuiClientBuilder::default().build_testnet().await?;
     println!("Sui testnet version is: {}", client.api_version());
-    let mut wallet = retrieve_wallet()?;
+    let mut wallet = retrieve_wallet(SignatureScheme::ED25519)?;
     assert!(wallet.get_addresses().len() >= 2);
     let active_address = wallet.active_address()?;
 
     println!("Wallet active address is: {active_address}");
     Ok((client, active_address))
+}
+
+/// Like [`setup_for_write`], but designates one wallet address as the
+/// sponsor and a different one as the sender, so the workshop can
+/// demonstrate fee-delegated (sponsored) transactions.
+pub async fn setup_for_sponsored() -> Result<(SuiClient, SuiAddress, SuiAddress), anyhow::Error> {
+    let (client, sender) = setup_for_read().await?;
+
+    let wallet = retrieve_wallet(SignatureScheme::ED25519)?;
+    let sponsor = wallet
+        .get_addresses()
+        .into_iter()
+        .find(|address| address != &sender)
+        .expect("Cannot get the sponsor address needed for sponsored writes. Aborting");
+
+    // make sure the sponsor actually has SUI to pay gas with
+    let sponsor_coin = fetch_coin(&client, &sponsor).await?;
+    if sponsor_coin.is_none() {
+        request_tokens_from_faucet(sponsor, &client).await?;
+    }
+
+    Ok((client, sender, sponsor))
 }
\ No newline at end of file